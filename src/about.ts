@@ -74,7 +74,7 @@ document.addEventListener("DOMContentLoaded", async () => {
           await invoke("quit_app");
         }
       } catch (err) {
-        alert('Failed to reset application: ' + err);
+        alert('Failed to reset application: ' + ((err as { message?: string })?.message ?? err));
         console.error("Reset error:", err);
       }
     }
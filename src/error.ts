@@ -7,11 +7,13 @@ interface ErrorData {
     timestamp: string;
     category?: string;
     details?: string;
+    code?: string;
 }
 
 let errors: ErrorData[] = [];
 let filteredErrors: ErrorData[] = [];
 let searchQuery = '';
+let categoryFilter = '';
 let autoScroll = true;
 
 // DOM Elements
@@ -19,14 +21,51 @@ const errorList = document.getElementById('errorList') as HTMLDivElement;
 const closeBtn = document.getElementById('closeBtn') as HTMLButtonElement;
 const clearBtn = document.getElementById('clearBtn') as HTMLButtonElement;
 const exportBtn = document.getElementById('exportBtn') as HTMLButtonElement;
+const diagnosticsBtn = document.getElementById('diagnosticsBtn') as HTMLButtonElement;
+const logTailBtn = document.getElementById('logTailBtn') as HTMLButtonElement;
+const openLogBtn = document.getElementById('openLogBtn') as HTMLButtonElement;
+const logPanel = document.getElementById('logPanel') as HTMLDivElement;
+const logTail = document.getElementById('logTail') as HTMLPreElement;
+const logPath = document.getElementById('logPath') as HTMLSpanElement;
 const errorCount = document.getElementById('errorCount') as HTMLDivElement;
 const searchInput = document.getElementById('searchInput') as HTMLInputElement;
 const clearSearchBtn = document.getElementById('clearSearchBtn') as HTMLButtonElement;
 const autoScrollCheckbox = document.getElementById('autoScrollCheckbox') as HTMLInputElement;
 const filteredCount = document.getElementById('filteredCount') as HTMLDivElement;
+const categorySelect = document.getElementById('categoryFilter') as HTMLSelectElement;
+
+// Load the persisted error history (optionally a single category) from the backend
+async function loadErrorHistory() {
+    try {
+        errors = await invoke<ErrorData[]>('get_error_history', {
+            category: categoryFilter || null,
+        });
+    } catch (err) {
+        console.error('Failed to load error history:', err);
+        errors = [];
+    }
+    applyFilters();
+    renderErrors();
+}
+
+// Keep the category dropdown in sync with the categories seen so far
+function addCategoryOption(category?: string) {
+    const value = category || 'ERROR';
+    if (Array.from(categorySelect.options).some(option => option.value === value)) {
+        return;
+    }
+    const option = document.createElement('option');
+    option.value = value;
+    option.textContent = value;
+    categorySelect.appendChild(option);
+}
 
 // Add error to the list
 function addError(error: ErrorData) {
+    addCategoryOption(error.category);
+    if (categoryFilter && (error.category || 'ERROR') !== categoryFilter) {
+        return;
+    }
     errors.push(error);
     applyFilters();
     renderErrors();
@@ -49,6 +88,7 @@ function applyFilters() {
             error.message.toLowerCase().includes(query) ||
             error.category?.toLowerCase().includes(query) ||
             error.details?.toLowerCase().includes(query) ||
+            error.code?.toLowerCase().includes(query) ||
             error.timestamp.toLowerCase().includes(query)
         );
     }
@@ -141,6 +181,9 @@ function renderErrors() {
                         <span class="text-xs font-semibold px-2 py-1 rounded ${getSeverityColor(error.category)}">
                             ${error.category || 'ERROR'}
                         </span>
+                        ${error.code ? `
+                            <span class="text-xs font-mono text-gray-500 dark:text-gray-400">${escapeHtml(error.code)}</span>
+                        ` : ''}
                         <span class="text-xs text-gray-500 dark:text-gray-400 flex items-center">
                             <svg class="w-3 h-3 mr-1" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                                 <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z" />
@@ -196,7 +239,7 @@ function renderErrors() {
     const error = errors[index];
     if (!error) return;
     
-    const text = `[${error.category || 'ERROR'}] ${error.timestamp}\n${error.message}${error.details ? '\n\nDetails:\n' + error.details : ''}`;
+    const text = `[${error.category || 'ERROR'}]${error.code ? ' ' + error.code : ''} ${error.timestamp}\n${error.message}${error.details ? '\n\nDetails:\n' + error.details : ''}`;
     
     try {
         await navigator.clipboard.writeText(text);
@@ -230,10 +273,19 @@ function escapeHtml(text: string): string {
 }
 
 // Clear all errors
-clearBtn.addEventListener('click', () => {
+clearBtn.addEventListener('click', async () => {
     if (errors.length === 0) return;
     
-    if (confirm(`Are you sure you want to clear all ${errors.length} error(s)?`)) {
+    const scope = categoryFilter ? `${categoryFilter} ` : '';
+    if (confirm(`Are you sure you want to clear all ${errors.length} ${scope}error(s)?`)) {
+        try {
+            // With a category selected, only that category is removed from the history
+            await invoke('clear_error_history', { category: categoryFilter || null });
+        } catch (err) {
+            console.error('Failed to clear error history:', err);
+            showNotification('Failed to clear error history', true);
+            return;
+        }
         errors = [];
         filteredErrors = [];
         searchQuery = '';
@@ -241,7 +293,7 @@ clearBtn.addEventListener('click', () => {
         clearSearchBtn.classList.add('hidden');
         applyFilters();
         renderErrors();
-        showNotification('All errors cleared');
+        showNotification(categoryFilter ? `${categoryFilter} errors cleared` : 'All errors cleared');
     }
 });
 
@@ -253,7 +305,7 @@ exportBtn.addEventListener('click', async () => {
     }
     
     const text = errors.map(error => 
-        `[${error.category || 'ERROR'}] ${error.timestamp}\n${error.message}${error.details ? '\n\nDetails:\n' + error.details : ''}\n${'='.repeat(80)}`
+        `[${error.category || 'ERROR'}]${error.code ? ' ' + error.code : ''} ${error.timestamp}\n${error.message}${error.details ? '\n\nDetails:\n' + error.details : ''}\n${'='.repeat(80)}`
     ).join('\n\n');
     
     try {
@@ -265,6 +317,74 @@ exportBtn.addEventListener('click', async () => {
     }
 });
 
+// Export a redacted diagnostics bundle for support
+diagnosticsBtn.addEventListener('click', async () => {
+    const hostname = prompt('Include the generated .rdp file for a host (optional):', '');
+    if (hostname === null) return;
+
+    try {
+        const path = await invoke<string>('export_diagnostics_bundle', {
+            path: null,
+            hostname: hostname.trim() || null,
+        });
+        showNotification(`Diagnostics saved to ${path}`);
+    } catch (err) {
+        console.error('Failed to export diagnostics:', err);
+        showNotification('Failed to export diagnostics bundle', true);
+    }
+});
+
+interface LogTail {
+    path: string;
+    exists: boolean;
+    size_bytes: number;
+    lines: string[];
+}
+
+let logTailTimer: number | null = null;
+
+async function refreshLogTail() {
+    try {
+        const tail = await invoke<LogTail>('get_log_tail', { lines: 300 });
+        logPath.textContent = tail.path;
+        const atBottom = logTail.scrollTop + logTail.clientHeight >= logTail.scrollHeight - 10;
+        logTail.textContent = tail.exists
+            ? tail.lines.join('\n')
+            : 'No log file yet. Enable logging from the tray menu (Debug Logging).';
+        if (atBottom) {
+            logTail.scrollTop = logTail.scrollHeight;
+        }
+    } catch (err) {
+        console.error('Failed to read log file:', err);
+        logTail.textContent = 'Failed to read log file';
+    }
+}
+
+// Show the end of the debug log, refreshing while the panel is open
+logTailBtn.addEventListener('click', async () => {
+    const show = logPanel.classList.contains('hidden');
+    logPanel.classList.toggle('hidden', !show);
+    logTailBtn.textContent = show ? 'Hide Log' : 'Show Log';
+
+    if (show) {
+        await refreshLogTail();
+        logTail.scrollTop = logTail.scrollHeight;
+        logTailTimer = window.setInterval(refreshLogTail, 2000);
+    } else if (logTailTimer !== null) {
+        window.clearInterval(logTailTimer);
+        logTailTimer = null;
+    }
+});
+
+openLogBtn.addEventListener('click', async () => {
+    try {
+        await invoke('open_log_file');
+    } catch (err) {
+        console.error('Failed to open log file:', err);
+        showNotification('No log file to open. Enable logging from the tray menu.', true);
+    }
+});
+
 // Search functionality
 searchInput.addEventListener('input', (e) => {
     searchQuery = (e.target as HTMLInputElement).value;
@@ -281,6 +401,12 @@ clearSearchBtn.addEventListener('click', () => {
     renderErrors();
 });
 
+// Category filter
+categorySelect.addEventListener('change', async () => {
+    categoryFilter = categorySelect.value;
+    await loadErrorHistory();
+});
+
 // Auto-scroll toggle
 autoScrollCheckbox.addEventListener('change', (e) => {
     autoScroll = (e.target as HTMLInputElement).checked;
@@ -337,5 +463,6 @@ async function initializeTheme() {
         // The window will be shown automatically by the backend
     });
 
-    renderErrors();
+    await loadErrorHistory();
+    errors.forEach(error => addCategoryOption(error.category));
 })();
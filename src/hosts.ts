@@ -14,16 +14,50 @@ interface StoredCredentials {
   password: string;
 }
 
+// Subset of the backend Settings that affects this window
+interface Settings {
+  store_passwords: boolean;
+  lock_host_catalogue: boolean;
+}
+
 let hosts: Host[] = [];
 let filteredHosts: Host[] = [];
+let catalogueLocked = false;
+let passwordsLocked = false;
+
+// Structured error returned by backend commands (QuickRdpError)
+interface CommandError {
+  code: string;
+  category: string;
+  message: string;
+  details?: string | null;
+}
+
+function isCommandError(err: unknown): err is CommandError {
+  return typeof err === "object" && err !== null && "code" in err && "message" in err;
+}
+
+function errorMessage(err: unknown): string {
+  return isCommandError(err) ? err.message : String(err);
+}
 
 // Function to show errors in the dedicated error window
-async function showError(message: string, category?: string, details?: string) {
+async function showError(message: string, category?: string, error?: unknown) {
+  let details: string | undefined;
+  let code: string | undefined;
+  if (isCommandError(error)) {
+    details = error.details ? `${error.message}\n${error.details}` : error.message;
+    code = error.code;
+  } else if (error !== undefined) {
+    details = String(error);
+  }
+
   try {
     await invoke("show_error", {
       message,
       category: category || "ERROR",
       details: details || undefined,
+      code,
     });
   } catch (err) {
     console.error("Failed to show error window:", err);
@@ -58,6 +92,29 @@ async function initializeTheme() {
   });
 }
 
+// Disable host editing and password saving when the administrator policy forbids them
+function applySettings(settings: Settings) {
+  catalogueLocked = settings.lock_host_catalogue;
+  passwordsLocked = !settings.store_passwords;
+  for (const id of ["addHost", "scanDomain", "deleteAllHosts"]) {
+    const button = document.getElementById(id) as HTMLButtonElement | null;
+    if (button) {
+      button.disabled = catalogueLocked;
+      button.title = catalogueLocked ? "The host list is managed by your administrator" : "";
+    }
+  }
+  renderHosts();
+}
+
+async function loadSettings() {
+  try {
+    applySettings(await invoke<Settings>("get_settings"));
+  } catch (error) {
+    console.error("Failed to load settings:", error);
+  }
+  await listen<Settings>("settings-changed", (event) => applySettings(event.payload));
+}
+
 function setupEventListeners() {
   console.log("Setting up event listeners for hosts window");
   
@@ -94,7 +151,7 @@ function setupEventListeners() {
       await showError(
         "Failed to save host to database",
         "CSV_OPERATIONS",
-        String(error)
+        error
       );
     }
   });
@@ -112,7 +169,7 @@ function setupEventListeners() {
         await showError(
           "Failed to return to main window",
           "WINDOW",
-          String(err)
+          err
         );
       }
     });
@@ -189,11 +246,11 @@ function setupEventListeners() {
       
     } catch (error) {
       console.error("Failed to scan domain:", error);
-      showToast(`Failed to scan domain: ${error}`, 'error');
+      showToast(`Failed to scan domain: ${errorMessage(error)}`, 'error');
       await showError(
         "Failed to scan Active Directory domain",
         "LDAP_SCAN",
-        String(error)
+        error
       );
     } finally {
       submitButton.disabled = false;
@@ -233,7 +290,7 @@ async function loadHosts() {
     await showError(
       "Failed to load hosts from database",
       "CSV_OPERATIONS",
-      String(error)
+      error
     );
   }
 }
@@ -260,17 +317,17 @@ function renderHosts() {
         <td class="text-center">${host.description || ''}</td>
         <td class="text-center">${host.last_connected || 'Never'}</td>
         <td class="text-center space-x-2">
-          <button class="btn btn-xs btn-ghost" onclick="window.saveHostCredentials('${host.hostname}')">
+          <button class="btn btn-xs btn-ghost" ${passwordsLocked ? 'disabled title="Saving passwords is disabled by your administrator"' : ''} onclick="window.saveHostCredentials('${host.hostname}')">
             <svg xmlns="http://www.w3.org/2000/svg" class="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor">
               <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 7a2 2 0 012 2m4 0a6 6 0 01-7.743 5.743L11 17H9v2H7v2H4a1 1 0 01-1-1v-2.586a1 1 0 01.293-.707l5.964-5.964A6 6 0 1121 9z" />
             </svg>
           </button>
-          <button class="btn btn-xs btn-ghost" onclick="window.editHost('${host.hostname}')">
+          <button class="btn btn-xs btn-ghost" ${catalogueLocked ? 'disabled' : ''} onclick="window.editHost('${host.hostname}')">
             <svg xmlns="http://www.w3.org/2000/svg" class="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor">
               <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15.232 5.232l3.536 3.536m-2.036-5.036a2.5 2.5 0 113.536 3.536L6.5 21.036H3v-3.572L16.732 3.732z" />
             </svg>
           </button>
-          <button class="btn btn-xs btn-ghost text-error" onclick="window.deleteHost('${host.hostname}')">
+          <button class="btn btn-xs btn-ghost text-error" ${catalogueLocked ? 'disabled' : ''} onclick="window.deleteHost('${host.hostname}')">
             <svg xmlns="http://www.w3.org/2000/svg" class="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor">
               <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16" />
             </svg>
@@ -302,7 +359,7 @@ window.deleteHost = async (hostname: string) => {
     await showError(
       "Failed to delete host from database",
       "CSV_OPERATIONS",
-      String(error)
+      error
     );
   }
 };
@@ -434,11 +491,11 @@ window.saveHostCredentials = async (hostname: string) => {
                 modal.close();
             } catch (error) {
                 console.error("Failed to save host credentials:", error);
-                showToast(`Failed to save credentials: ${error}`, 'error');
+                showToast(`Failed to save credentials: ${errorMessage(error)}`, 'error');
                 await showError(
                     "Failed to save credentials for host",
                     "HOST_CREDENTIALS",
-                    String(error)
+                    error
                 );
             }
         };
@@ -450,11 +507,11 @@ window.saveHostCredentials = async (hostname: string) => {
         
     } catch (error) {
         console.error("Failed to manage host credentials:", error);
-        showToast(`Failed to manage credentials: ${error}`, 'error');
+        showToast(`Failed to manage credentials: ${errorMessage(error)}`, 'error');
         await showError(
             "Failed to open credentials management dialog",
             "HOST_CREDENTIALS",
-            String(error)
+            error
         );
     }
 };
@@ -468,11 +525,11 @@ async function deleteAllHosts() {
     showToast("All hosts deleted successfully", 'success');
   } catch (error) {
     console.error("Failed to delete all hosts:", error);
-    showToast(`Failed to delete all hosts: ${error}`, 'error');
+    showToast(`Failed to delete all hosts: ${errorMessage(error)}`, 'error');
     await showError(
       "Failed to delete all hosts",
       "CSV_OPERATIONS",
-      String(error)
+      error
     );
   }
 }
@@ -491,6 +548,7 @@ document.addEventListener("DOMContentLoaded", async () => {
   try {
     await initializeTheme();
     setupEventListeners();
+    await loadSettings();
     await loadHosts();
     console.log("Hosts window initialized successfully");
   } catch (error) {
@@ -498,7 +556,7 @@ document.addEventListener("DOMContentLoaded", async () => {
     await showError(
       "Failed to initialize Manage Hosts window",
       "INITIALIZATION",
-      String(error)
+      error
     );
   }
   
@@ -545,7 +603,7 @@ document.addEventListener("DOMContentLoaded", async () => {
           await invoke("quit_app");
         }
       } catch (err) {
-        alert('Failed to reset application: ' + err);
+        alert('Failed to reset application: ' + errorMessage(err));
         console.error("Reset error:", err);
       }
     }
@@ -6,6 +6,13 @@ interface StoredCredentials {
   password: string;
 }
 
+interface VaultStatus {
+  backend: "credential_manager" | "vault";
+  exists: boolean;
+  unlocked: boolean;
+  auto_lock_minutes: number;
+}
+
 interface Host {
     hostname: string;
     description: string;
@@ -34,13 +41,39 @@ function showNotification(message: string, isError: boolean = false) {
   }, 1000);
 }
 
+// Structured error returned by backend commands (QuickRdpError)
+interface CommandError {
+  code: string;
+  category: string;
+  message: string;
+  details?: string | null;
+}
+
+function isCommandError(err: unknown): err is CommandError {
+  return typeof err === "object" && err !== null && "code" in err && "message" in err;
+}
+
+function errorMessage(err: unknown): string {
+  return isCommandError(err) ? err.message : String(err);
+}
+
 // Function to show errors in the dedicated error window
-async function showError(message: string, category?: string, details?: string) {
+async function showError(message: string, category?: string, error?: unknown) {
+  let details: string | undefined;
+  let code: string | undefined;
+  if (isCommandError(error)) {
+    details = error.details ? `${error.message}\n${error.details}` : error.message;
+    code = error.code;
+  } else if (error !== undefined) {
+    details = String(error);
+  }
+
   try {
     await invoke("show_error", {
       message,
       category: category || "ERROR",
       details: details || undefined,
+      code,
     });
   } catch (err) {
     console.error("Failed to show error window:", err);
@@ -178,6 +211,53 @@ async function checkCredentialsExist() {
     updateButtonStates(false);
   }
 }
+// Show the master password prompt instead of the login form while the vault is locked.
+// Resolves once the credential store is usable.
+async function ensureVaultUnlocked(): Promise<void> {
+  const loginForm = document.querySelector("#login-form") as HTMLFormElement | null;
+  const vaultForm = document.querySelector("#vault-form") as HTMLFormElement | null;
+  const vaultTitle = document.querySelector("#vault-title") as HTMLElement | null;
+  const masterPassword = document.querySelector("#master-password") as HTMLInputElement | null;
+
+  let status: VaultStatus;
+  try {
+    status = await invoke<VaultStatus>("get_vault_status");
+  } catch (err) {
+    console.error("Error checking vault status:", err);
+    return;
+  }
+
+  if (status.backend !== "vault" || status.unlocked || !loginForm || !vaultForm) {
+    return;
+  }
+
+  if (vaultTitle) {
+    vaultTitle.textContent = status.exists ? "Unlock Vault" : "Create Vault";
+  }
+  loginForm.classList.add("hidden");
+  vaultForm.classList.remove("hidden");
+  masterPassword?.focus();
+
+  return new Promise((resolve) => {
+    const onSubmit = async (e: Event) => {
+      e.preventDefault();
+      try {
+        await invoke(status.exists ? "unlock_vault" : "create_vault", {
+          masterPassword: masterPassword?.value ?? "",
+        });
+        if (masterPassword) masterPassword.value = "";
+        vaultForm.removeEventListener("submit", onSubmit);
+        vaultForm.classList.add("hidden");
+        loginForm.classList.remove("hidden");
+        resolve();
+      } catch (err) {
+        await showError("Failed to unlock the QuickRDP vault", "VAULT", err);
+      }
+    };
+    vaultForm.addEventListener("submit", onSubmit);
+  });
+}
+
 async function initializeTheme() {
   let defaultTheme = 'dark';
   
@@ -355,7 +435,7 @@ async function loadAllHosts() {
         await showError(
             "Failed to load hosts list",
             "CSV_OPERATIONS",
-            String(err)
+            err
         );
     }
 }
@@ -435,6 +515,19 @@ document.addEventListener("DOMContentLoaded", async () => {
             deleteBtn.classList.add("opacity-50", "cursor-not-allowed");
         }
 
+        // Unlock the vault (if it is the credential store) before reading credentials
+        document.querySelector("#vault-cancel-btn")?.addEventListener("click", async () => {
+            await invoke("quit_app");
+        });
+        await ensureVaultUnlocked();
+
+        // Re-prompt when the vault auto-locks
+        await listen("vault-locked", async () => {
+            await invoke("show_login_window");
+            await ensureVaultUnlocked();
+            checkCredentialsExist();
+        });
+
         // Check for existing credentials FIRST (before adding event listeners that might cancel the timer)
         checkCredentialsExist();
 
@@ -478,7 +571,7 @@ document.addEventListener("DOMContentLoaded", async () => {
                     await showError(
                         "Failed to delete stored credentials",
                         "CREDENTIALS",
-                        String(err)
+                        err
                     );
                 }
             });
@@ -526,7 +619,7 @@ document.addEventListener("DOMContentLoaded", async () => {
                 await showError(
                     "Failed to save credentials to Windows Credential Manager",
                     "CREDENTIALS",
-                    String(err)
+                    err
                 );
             }
         });
@@ -576,7 +669,7 @@ document.addEventListener("DOMContentLoaded", async () => {
                     await invoke("quit_app");
                 }
             } catch (err) {
-                alert('Failed to reset application: ' + err);
+                alert('Failed to reset application: ' + errorMessage(err));
                 console.error("Reset error:", err);
             }
         }